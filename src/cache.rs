@@ -0,0 +1,61 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::geocode::Point;
+use crate::provider::{Report, WeatherProvider};
+
+/// Default polling interval (15 minutes) to pass to [`spawn_updater`];
+/// `spawn_updater` itself always uses the interval it is given.
+pub const DEFAULT_UPDATE_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Shared, cheaply-cloneable handle to the most recently fetched [`Report`].
+///
+/// Reading the cache never blocks on network I/O; it just returns whatever
+/// the background updater last stored.
+#[derive(Clone, Default)]
+pub struct WeatherCache {
+    report: Arc<Mutex<Option<Report>>>,
+}
+
+impl WeatherCache {
+    pub fn new() -> Self {
+        WeatherCache::default()
+    }
+
+    /// Returns the most recently fetched report, if any fetch has succeeded yet.
+    pub fn get(&self) -> Option<Report> {
+        self.report.lock().expect("weather cache lock poisoned").clone()
+    }
+}
+
+/// Spawns a background task that repeatedly fetches weather for `point` via
+/// `provider`, keeping `WeatherCache` up to date every `interval`.
+///
+/// On a failed fetch the error is logged and the previous cached value (if
+/// any) is kept rather than cleared.
+pub fn spawn_updater<P>(provider: P, point: Point, interval: Duration) -> WeatherCache
+where
+    P: WeatherProvider + Send + Sync + 'static,
+{
+    let cache = WeatherCache::new();
+    let updater_cache = cache.clone();
+
+    tokio::spawn(async move {
+        loop {
+            match provider.fetch(point.lat, point.lng).await {
+                Ok(report) => {
+                    *updater_cache
+                        .report
+                        .lock()
+                        .expect("weather cache lock poisoned") = Some(report);
+                }
+                Err(err) => {
+                    log::error!("weather update failed, keeping previous report: {err}");
+                }
+            }
+            tokio::time::sleep(interval).await;
+        }
+    });
+
+    cache
+}
@@ -0,0 +1,82 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use eyre::{eyre, Error};
+use serde::Deserialize;
+
+use crate::geocode::geocode;
+use crate::provider::{Report, WeatherProvider};
+use crate::providers::OpenWeatherMapProvider;
+
+/// TOML-backed configuration for serving several people's local weather from
+/// one process, e.g. a chat bot.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    pub api_key: String,
+    #[serde(default)]
+    pub users: Vec<ConfigUser>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfigUser {
+    pub name: String,
+    pub location: String,
+}
+
+impl Config {
+    /// Loads and parses a `Config` from a TOML file at `path`.
+    pub fn from_path(path: &Path) -> Result<Config, Error> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| eyre!("failed to read config file {}: {err}", path.display()))?;
+        let config: Config = toml::from_str(&contents)
+            .map_err(|err| eyre!("failed to parse config file {}: {err}", path.display()))?;
+        if config.api_key.is_empty() {
+            return Err(eyre!("config file {} has an empty api_key", path.display()));
+        }
+        Ok(config)
+    }
+
+    /// Builds an [`OpenWeatherMapProvider`] using this config's `api_key`,
+    /// ready to wrap in an [`Arc`] and pass to [`fetch_all`].
+    pub fn open_weather_map_provider(&self) -> OpenWeatherMapProvider {
+        OpenWeatherMapProvider::new(&self.api_key)
+    }
+}
+
+/// Geocodes and fetches the current weather for every [`ConfigUser`] in
+/// `config` through `provider`, concurrently. Each result is paired with the
+/// user's `name` so callers can tell whose fetch failed.
+///
+/// `provider` is typically built from the same config via
+/// [`Config::open_weather_map_provider`].
+pub async fn fetch_all<P>(provider: Arc<P>, config: &Config) -> Vec<(String, Result<Report, Error>)>
+where
+    P: WeatherProvider + Send + Sync + 'static,
+{
+    let mut set = tokio::task::JoinSet::new();
+
+    for user in config.users.clone() {
+        let provider = Arc::clone(&provider);
+        set.spawn(async move {
+            let result = fetch_for_user(provider.as_ref(), &user.location).await;
+            (user.name, result)
+        });
+    }
+
+    let mut results = Vec::with_capacity(set.len());
+    while let Some(joined) = set.join_next().await {
+        match joined {
+            Ok(pair) => results.push(pair),
+            Err(join_err) => results.push(("<unknown>".to_string(), Err(eyre!(join_err)))),
+        }
+    }
+    results
+}
+
+async fn fetch_for_user<P>(provider: &P, location: &str) -> Result<Report, Error>
+where
+    P: WeatherProvider,
+{
+    let point = geocode(location).await?;
+    provider.fetch(point.lat, point.lng).await
+}
@@ -0,0 +1,94 @@
+use eyre::{eyre, Error};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::provider::{Report, WeatherProvider};
+
+const GEOCODING_URL: &str = "https://geocoding-api.open-meteo.com/v1/search";
+
+/// A coordinate pair, as required by [`crate::DarkskyResult::new`] and the
+/// [`crate::WeatherProvider`] backends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub lat: Decimal,
+    pub lng: Decimal,
+}
+
+/// A named place resolved by [`geocode`], with coordinates still in `f32`
+/// as returned by the geocoding API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct City {
+    pub city: String,
+    #[serde(default)]
+    pub state_id: String,
+    pub lat: f32,
+    pub lng: f32,
+}
+
+impl City {
+    pub fn into_point(self) -> Point {
+        Point {
+            lat: Decimal::try_from(self.lat).unwrap_or_default(),
+            lng: Decimal::try_from(self.lng).unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GeocodingResult {
+    name: String,
+    #[serde(default)]
+    admin1: String,
+    latitude: f32,
+    longitude: f32,
+}
+
+impl From<GeocodingResult> for City {
+    fn from(result: GeocodingResult) -> City {
+        City {
+            city: result.name,
+            state_id: result.admin1,
+            lat: result.latitude,
+            lng: result.longitude,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GeocodingResponse {
+    #[serde(default)]
+    results: Vec<GeocodingResult>,
+}
+
+/// Resolves a human place name like `"Portland, OR"` to a [`Point`] via the
+/// Open-Meteo geocoding API.
+pub async fn geocode(name: &str) -> Result<Point, Error> {
+    let response: GeocodingResponse = crate::http_client()
+        .get(GEOCODING_URL)
+        .query(&[("name", name), ("count", "1")])
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    let city: City = response
+        .results
+        .into_iter()
+        .next()
+        .ok_or_else(|| eyre!("no geocoding results for place name {:?}", name))?
+        .into();
+
+    Ok(city.into_point())
+}
+
+/// Resolves `place` to a [`Point`] via [`geocode`], then fetches weather for
+/// it through `provider`. This is the live-backend equivalent of
+/// [`crate::DarkskyResult::for_place`], which can only ever hit DarkSky's
+/// dead endpoint.
+pub async fn fetch_for_place<P>(provider: &P, place: &str) -> Result<Report, Error>
+where
+    P: WeatherProvider,
+{
+    let point = geocode(place).await?;
+    provider.fetch(point.lat, point.lng).await
+}
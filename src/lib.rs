@@ -5,8 +5,42 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json;
 
+pub mod cache;
+pub mod config;
+pub mod geocode;
+pub mod provider;
+pub mod providers;
+pub mod units;
+
+pub use cache::{spawn_updater, WeatherCache};
+pub use config::{fetch_all, Config, ConfigUser};
+pub use geocode::{fetch_for_place, City, Point};
+pub use provider::{Condition, Conditions, Forecast, Location, Report, WeatherProvider};
+pub use units::Units;
+
 const DARKSKY_FORECAST_URL: &str = "https://api.darksky.net/forecast/";
 
+/// Returns the [`reqwest::Client`] shared by every HTTP call in this crate,
+/// built once with a `User-Agent` identifying this crate rather than
+/// reqwest's default. Reusing one client (rather than building a fresh one
+/// per call) lets `reqwest` pool and keep connections alive, which matters
+/// for long-running pollers like [`cache::spawn_updater`].
+pub(crate) fn http_client() -> reqwest::Client {
+    static CLIENT: std::sync::OnceLock<reqwest::Client> = std::sync::OnceLock::new();
+    CLIENT
+        .get_or_init(|| {
+            reqwest::Client::builder()
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build()
+                .expect("building the shared reqwest client should never fail")
+        })
+        .clone()
+}
+
 type UnixTime = u64;
 type Temperature = f32;
 type Bearing = u16;
@@ -142,50 +176,90 @@ struct Flags {
     units: String,
 }
 
-fn get_weather_icon(iconstr: &str) -> &str {
-    match iconstr {
-        "clear-day" => "☀️",
-        "clear-night" => "🌙",
-        "rain" => "🌧",
-        "snow" => "🌨",
-        "sleet" => "🌨",
-        "wind" => "💨",
-        "fog" => "🌫",
-        "cloudy" => "☁️",
-        "partly-cloudy-day" => "⛅️",
-        "partly-cloudy-night" => "🌙",
-        "hail" => "🌧",
-        "thunderstorm" => "⛈",
-        "tornado" => "🌪",
-        _ => "",
-    }
-}
-
 impl DarkskyResult {
     pub async fn new(api_key: &str, lat: Decimal, lng: Decimal) -> Result<DarkskyResult, Error> {
-        let request_url = format!(
+        DarkskyResult::fetch(api_key, lat, lng, None).await
+    }
+
+    /// Like [`DarkskyResult::new`], but sends DarkSky's `units` query
+    /// parameter so the response is already expressed in `units`.
+    pub async fn with_units(
+        api_key: &str,
+        lat: Decimal,
+        lng: Decimal,
+        units: Units,
+    ) -> Result<DarkskyResult, Error> {
+        DarkskyResult::fetch(api_key, lat, lng, Some(units)).await
+    }
+
+    /// Resolves `place` to coordinates via [`geocode::geocode`], then fetches
+    /// the forecast for them, e.g. `for_place(key, "Portland, OR")`.
+    ///
+    /// This goes through [`DarkskyResult::new`], which hits DarkSky's dead
+    /// live endpoint and so can never succeed with a real key. It's kept
+    /// only for source compatibility with existing callers of
+    /// `DarkskyResult`; use [`geocode::fetch_for_place`] with a live
+    /// [`WeatherProvider`] instead.
+    pub async fn for_place(api_key: &str, place: &str) -> Result<DarkskyResult, Error> {
+        let point = geocode::geocode(place).await?;
+        DarkskyResult::new(api_key, point.lat, point.lng).await
+    }
+
+    async fn fetch(
+        api_key: &str,
+        lat: Decimal,
+        lng: Decimal,
+        units: Option<Units>,
+    ) -> Result<DarkskyResult, Error> {
+        let mut request_url = format!(
             "{}{}/{},{}",
             DARKSKY_FORECAST_URL,
             urlencoding::encode(api_key),
             lat,
             lng,
         );
-        let response = reqwest::get(request_url).await?;
+        if let Some(units) = units {
+            request_url.push_str(&format!("?units={}", units.as_param()));
+        }
+        let response = http_client().get(request_url).send().await?;
         let response_body = response.text().await?;
         Ok(serde_json::from_str::<DarkskyResult>(&response_body)?)
     }
+
     fn get_unit(&self) -> &str {
         match self.flags.units.as_str() {
             "us" => "F",
             _ => "C",
         }
     }
+
+    /// Compares the current temperature against the next hourly reading (or,
+    /// failing that, the upcoming daily high) and returns an arrow for
+    /// whether it's rising, falling, or holding steady.
+    pub fn trend(&self) -> char {
+        const TREND_EPSILON: Temperature = 0.5;
+
+        let future = self
+            .hourly
+            .data
+            .get(1)
+            .map(|hour| hour.temperature)
+            .or_else(|| self.daily.data.first().map(|day| day.temperature_high));
+
+        match future {
+            Some(future) if future > self.currently.temperature + TREND_EPSILON => '↑',
+            Some(future) if future < self.currently.temperature - TREND_EPSILON => '↓',
+            _ => '→',
+        }
+    }
+
     fn get_current_weather_str(&self) -> String {
         format!(
-            "{:.1}°{} {} {}",
+            "{:.1}°{} {} {} {}",
             self.currently.temperature,
             self.get_unit(),
-            get_weather_icon(self.currently.icon.as_str()),
+            provider::get_weather_icon(Condition::from_darksky_icon(&self.currently.icon)),
+            self.trend(),
             self.currently.summary
         )
     }
@@ -0,0 +1,262 @@
+use std::fmt::{self, Display};
+
+use async_trait::async_trait;
+use eyre::Error;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::units::{self, Units};
+use crate::{
+    Bearing, CloudCover, DarkskyResult, Distance, Humidity, Pressure, Speed, Temperature, UnixTime,
+};
+
+/// A location a [`Report`] was produced for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Location {
+    pub lat: Decimal,
+    pub lng: Decimal,
+    pub timezone: Option<String>,
+}
+
+/// Normalized sky/precipitation condition, independent of any single provider's vocabulary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Condition {
+    ClearDay,
+    ClearNight,
+    Rain,
+    Snow,
+    Sleet,
+    Wind,
+    Fog,
+    Cloudy,
+    PartlyCloudyDay,
+    PartlyCloudyNight,
+    Hail,
+    Thunderstorm,
+    Tornado,
+    Unknown,
+}
+
+impl Condition {
+    /// Maps a DarkSky `icon` string onto the normalized condition set.
+    pub(crate) fn from_darksky_icon(icon: &str) -> Condition {
+        match icon {
+            "clear-day" => Condition::ClearDay,
+            "clear-night" => Condition::ClearNight,
+            "rain" => Condition::Rain,
+            "snow" => Condition::Snow,
+            "sleet" => Condition::Sleet,
+            "wind" => Condition::Wind,
+            "fog" => Condition::Fog,
+            "cloudy" => Condition::Cloudy,
+            "partly-cloudy-day" => Condition::PartlyCloudyDay,
+            "partly-cloudy-night" => Condition::PartlyCloudyNight,
+            "hail" => Condition::Hail,
+            "thunderstorm" => Condition::Thunderstorm,
+            "tornado" => Condition::Tornado,
+            _ => Condition::Unknown,
+        }
+    }
+}
+
+/// Current conditions at a point in time, normalized across providers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conditions {
+    pub time: UnixTime,
+    pub condition: Condition,
+    pub summary: String,
+    pub temperature: Temperature,
+    pub feels_like: Temperature,
+    pub humidity: Humidity,
+    pub pressure: Pressure,
+    pub wind_speed: Speed,
+    pub wind_bearing: Bearing,
+    pub cloud_cover: CloudCover,
+    pub visibility: Distance,
+}
+
+/// A single hourly or daily forecast entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Forecast {
+    pub time: UnixTime,
+    pub condition: Condition,
+    pub summary: String,
+    pub temperature: Temperature,
+    pub temperature_high: Option<Temperature>,
+    pub temperature_low: Option<Temperature>,
+}
+
+/// Provider-neutral weather report: the common shape every [`WeatherProvider`] produces.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub location: Location,
+    pub currently: Conditions,
+    pub hourly: Vec<Forecast>,
+    pub daily: Vec<Forecast>,
+    /// Human-readable attribution for where this data came from, e.g. `"OpenWeatherMap"`.
+    pub data_source: String,
+    /// The unit system every measurement in this report is currently expressed in.
+    pub units: Units,
+}
+
+impl Report {
+    /// Rescales every temperature, speed, pressure, and distance/visibility
+    /// measurement in this report from its current unit system to `target`.
+    pub fn convert_to(mut self, target: Units) -> Report {
+        let from = self.units;
+        if from == target {
+            return self;
+        }
+
+        self.currently = self.currently.converted(from, target);
+        self.hourly = self
+            .hourly
+            .into_iter()
+            .map(|forecast| forecast.converted(from, target))
+            .collect();
+        self.daily = self
+            .daily
+            .into_iter()
+            .map(|forecast| forecast.converted(from, target))
+            .collect();
+        self.units = target;
+        self
+    }
+
+    /// Compares the current temperature against the next hourly reading (or,
+    /// failing that, the upcoming daily high) and returns an arrow for
+    /// whether it's rising, falling, or holding steady.
+    pub fn trend(&self) -> char {
+        const TREND_EPSILON: Temperature = 0.5;
+
+        let future = self
+            .hourly
+            .get(1)
+            .map(|hour| hour.temperature)
+            .or_else(|| self.daily.first().and_then(|day| day.temperature_high));
+
+        match future {
+            Some(future) if future > self.currently.temperature + TREND_EPSILON => '↑',
+            Some(future) if future < self.currently.temperature - TREND_EPSILON => '↓',
+            _ => '→',
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.1}°{} {} {} {}",
+            self.currently.temperature,
+            self.units.temperature_label(),
+            get_weather_icon(self.currently.condition),
+            self.trend(),
+            self.currently.summary
+        )
+    }
+}
+
+impl Conditions {
+    fn converted(mut self, from: Units, to: Units) -> Conditions {
+        self.temperature = units::convert_temperature(self.temperature, from, to);
+        self.feels_like = units::convert_temperature(self.feels_like, from, to);
+        self.pressure = units::convert_pressure(self.pressure, from, to);
+        self.wind_speed = units::convert_speed(self.wind_speed, from, to);
+        self.visibility = units::convert_distance(self.visibility, from, to);
+        self
+    }
+}
+
+impl Forecast {
+    fn converted(mut self, from: Units, to: Units) -> Forecast {
+        self.temperature = units::convert_temperature(self.temperature, from, to);
+        self.temperature_high = self
+            .temperature_high
+            .map(|t| units::convert_temperature(t, from, to));
+        self.temperature_low = self
+            .temperature_low
+            .map(|t| units::convert_temperature(t, from, to));
+        self
+    }
+}
+
+impl From<DarkskyResult> for Report {
+    fn from(result: DarkskyResult) -> Report {
+        let currently = &result.currently;
+        Report {
+            location: Location {
+                lat: result.latitude,
+                lng: result.longitude,
+                timezone: Some(result.timezone.clone()),
+            },
+            currently: Conditions {
+                time: currently.time,
+                condition: Condition::from_darksky_icon(&currently.icon),
+                summary: currently.summary.clone(),
+                temperature: currently.temperature,
+                feels_like: currently.apparent_temperature,
+                humidity: currently.humidity,
+                pressure: currently.pressure,
+                wind_speed: currently.wind_speed,
+                wind_bearing: currently.wind_bearing,
+                cloud_cover: currently.cloud_cover,
+                visibility: currently.visibility,
+            },
+            hourly: result
+                .hourly
+                .data
+                .iter()
+                .map(|w| Forecast {
+                    time: w.time,
+                    condition: Condition::from_darksky_icon(&w.icon),
+                    summary: w.summary.clone(),
+                    temperature: w.temperature,
+                    temperature_high: None,
+                    temperature_low: None,
+                })
+                .collect(),
+            daily: result
+                .daily
+                .data
+                .iter()
+                .map(|d| Forecast {
+                    time: d.time,
+                    condition: Condition::from_darksky_icon(&d.icon),
+                    summary: d.summary.clone(),
+                    temperature: d.temperature_high,
+                    temperature_high: Some(d.temperature_high),
+                    temperature_low: Some(d.temperature_low),
+                })
+                .collect(),
+            data_source: "DarkSky".to_string(),
+            units: Units::from_darksky_param(&result.flags.units),
+        }
+    }
+}
+
+/// Maps a normalized [`Condition`] to the emoji used in display output.
+pub fn get_weather_icon(condition: Condition) -> &'static str {
+    match condition {
+        Condition::ClearDay => "☀️",
+        Condition::ClearNight => "🌙",
+        Condition::Rain => "🌧",
+        Condition::Snow => "🌨",
+        Condition::Sleet => "🌨",
+        Condition::Wind => "💨",
+        Condition::Fog => "🌫",
+        Condition::Cloudy => "☁️",
+        Condition::PartlyCloudyDay => "⛅️",
+        Condition::PartlyCloudyNight => "🌙",
+        Condition::Hail => "🌧",
+        Condition::Thunderstorm => "⛈",
+        Condition::Tornado => "🌪",
+        Condition::Unknown => "",
+    }
+}
+
+/// A source of [`Report`]s for a given coordinate, e.g. DarkSky, OpenWeatherMap, or NWS.
+#[async_trait]
+pub trait WeatherProvider {
+    async fn fetch(&self, lat: Decimal, lng: Decimal) -> Result<Report, Error>;
+}
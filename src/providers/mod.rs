@@ -0,0 +1,5 @@
+pub mod nws;
+pub mod openweathermap;
+
+pub use nws::NwsProvider;
+pub use openweathermap::OpenWeatherMapProvider;
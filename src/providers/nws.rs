@@ -0,0 +1,229 @@
+use async_trait::async_trait;
+use eyre::{eyre, Error};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::provider::{Condition, Conditions, Forecast, Location, Report, WeatherProvider};
+use crate::units::Units;
+use crate::UnixTime;
+
+const NWS_POINTS_URL: &str = "https://api.weather.gov/points";
+
+impl Condition {
+    /// Maps an NWS `shortForecast` string onto the normalized condition set.
+    ///
+    /// NWS describes conditions in free text (e.g. "Mostly Sunny", "Chance
+    /// Showers And Thunderstorms") rather than a fixed vocabulary, so this is
+    /// a best-effort keyword match.
+    fn from_nws_short_forecast(short_forecast: &str, is_daytime: bool) -> Condition {
+        let lower = short_forecast.to_lowercase();
+        if lower.contains("tornado") {
+            Condition::Tornado
+        } else if lower.contains("thunderstorm") {
+            Condition::Thunderstorm
+        } else if lower.contains("hail") {
+            Condition::Hail
+        } else if lower.contains("snow") || lower.contains("flurries") {
+            Condition::Snow
+        } else if lower.contains("sleet") || lower.contains("ice") {
+            Condition::Sleet
+        } else if lower.contains("rain") || lower.contains("showers") {
+            Condition::Rain
+        } else if lower.contains("fog") {
+            Condition::Fog
+        } else if lower.contains("wind") {
+            Condition::Wind
+        } else if lower.contains("partly") || lower.contains("mostly sunny") || lower.contains("mostly clear") {
+            if is_daytime {
+                Condition::PartlyCloudyDay
+            } else {
+                Condition::PartlyCloudyNight
+            }
+        } else if lower.contains("clear") || lower.contains("sunny") {
+            if is_daytime {
+                Condition::ClearDay
+            } else {
+                Condition::ClearNight
+            }
+        } else if lower.contains("cloudy") || lower.contains("overcast") {
+            Condition::Cloudy
+        } else {
+            Condition::Unknown
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct NwsPointsResponse {
+    properties: NwsPointsProperties,
+}
+
+#[derive(Debug, Deserialize)]
+struct NwsPointsProperties {
+    forecast: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NwsForecastResponse {
+    properties: NwsForecastProperties,
+}
+
+#[derive(Debug, Deserialize)]
+struct NwsForecastProperties {
+    periods: Vec<NwsPeriod>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NwsPeriod {
+    #[serde(rename = "startTime")]
+    start_time: String,
+    #[serde(rename = "isDaytime")]
+    is_daytime: bool,
+    temperature: f32,
+    #[serde(rename = "shortForecast")]
+    short_forecast: String,
+}
+
+/// [`WeatherProvider`] backed by the US National Weather Service API.
+///
+/// NWS has no API key but requires looking up the forecast endpoint for a
+/// coordinate via `/points/{lat},{lng}` before it can be fetched.
+pub struct NwsProvider {
+    user_agent: String,
+}
+
+impl NwsProvider {
+    /// NWS asks API consumers to identify themselves with a contact-bearing
+    /// `User-Agent`, e.g. `"(myapp.com, contact@myapp.com)"`.
+    pub fn new(user_agent: impl Into<String>) -> Self {
+        NwsProvider {
+            user_agent: user_agent.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl WeatherProvider for NwsProvider {
+    async fn fetch(&self, lat: Decimal, lng: Decimal) -> Result<Report, Error> {
+        let client = crate::http_client();
+
+        let points_url = format!("{}/{},{}", NWS_POINTS_URL, lat, lng);
+        let points: NwsPointsResponse = client
+            .get(points_url)
+            .header("User-Agent", &self.user_agent)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        let forecast: NwsForecastResponse = client
+            .get(&points.properties.forecast)
+            .header("User-Agent", &self.user_agent)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        let periods = forecast.properties.periods;
+        let first = periods
+            .first()
+            .ok_or_else(|| eyre!("NWS forecast response contained no periods"))?;
+
+        // NWS's forecast endpoint doesn't report humidity, pressure, wind
+        // speed/bearing, cloud cover, or visibility, so those are left at
+        // zero rather than fetched from a separate (gridpoint) endpoint.
+        let currently = Conditions {
+            time: parse_nws_time(&first.start_time),
+            condition: Condition::from_nws_short_forecast(&first.short_forecast, first.is_daytime),
+            summary: first.short_forecast.clone(),
+            temperature: first.temperature,
+            feels_like: first.temperature,
+            humidity: 0.0,
+            pressure: 0.0,
+            wind_speed: 0.0,
+            wind_bearing: 0,
+            cloud_cover: 0.0,
+            visibility: 0.0,
+        };
+
+        let daily = periods
+            .iter()
+            .map(|period| Forecast {
+                time: parse_nws_time(&period.start_time),
+                condition: Condition::from_nws_short_forecast(
+                    &period.short_forecast,
+                    period.is_daytime,
+                ),
+                summary: period.short_forecast.clone(),
+                temperature: period.temperature,
+                temperature_high: None,
+                temperature_low: None,
+            })
+            .collect();
+
+        Ok(Report {
+            location: Location {
+                lat,
+                lng,
+                timezone: None,
+            },
+            currently,
+            hourly: Vec::new(),
+            daily,
+            data_source: "National Weather Service".to_string(),
+            // NWS forecast periods report temperature in Fahrenheit.
+            units: Units::Us,
+        })
+    }
+}
+
+fn parse_nws_time(start_time: &str) -> UnixTime {
+    chrono::DateTime::parse_from_rfc3339(start_time)
+        .map(|dt| dt.timestamp() as UnixTime)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mostly_sunny_is_partly_cloudy_not_clear() {
+        assert_eq!(
+            Condition::from_nws_short_forecast("Mostly Sunny", true),
+            Condition::PartlyCloudyDay
+        );
+        assert_eq!(
+            Condition::from_nws_short_forecast("Mostly Clear", false),
+            Condition::PartlyCloudyNight
+        );
+    }
+
+    #[test]
+    fn partly_cloudy_is_reachable() {
+        assert_eq!(
+            Condition::from_nws_short_forecast("Partly Cloudy", true),
+            Condition::PartlyCloudyDay
+        );
+    }
+
+    #[test]
+    fn plain_clear_and_sunny_stay_clear() {
+        assert_eq!(
+            Condition::from_nws_short_forecast("Sunny", true),
+            Condition::ClearDay
+        );
+        assert_eq!(
+            Condition::from_nws_short_forecast("Clear", false),
+            Condition::ClearNight
+        );
+    }
+
+    #[test]
+    fn thunderstorms_outrank_rain() {
+        assert_eq!(
+            Condition::from_nws_short_forecast("Chance Showers And Thunderstorms", true),
+            Condition::Thunderstorm
+        );
+    }
+}
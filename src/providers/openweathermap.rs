@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use eyre::Error;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::provider::{Condition, Conditions, Forecast, Location, Report, WeatherProvider};
+use crate::units::{self, Units};
+
+const OWM_WEATHER_URL: &str = "https://api.openweathermap.org/data/2.5/weather";
+const OWM_FORECAST_URL: &str = "https://api.openweathermap.org/data/2.5/forecast";
+
+impl Condition {
+    /// Maps an OpenWeatherMap `weather[].main` value onto the normalized condition set.
+    fn from_owm_main(main: &str) -> Condition {
+        match main {
+            "Clear" => Condition::ClearDay,
+            "Clouds" => Condition::Cloudy,
+            "Rain" | "Drizzle" => Condition::Rain,
+            "Snow" => Condition::Snow,
+            "Thunderstorm" => Condition::Thunderstorm,
+            "Mist" | "Smoke" | "Haze" | "Dust" | "Fog" | "Sand" | "Ash" => Condition::Fog,
+            "Squall" => Condition::Wind,
+            "Tornado" => Condition::Tornado,
+            _ => Condition::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OwmWeatherCondition {
+    main: String,
+    description: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OwmMain {
+    temp: f32,
+    feels_like: f32,
+    humidity: f32,
+    pressure: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OwmWind {
+    speed: f32,
+    #[serde(default)]
+    deg: u16,
+}
+
+#[derive(Debug, Deserialize)]
+struct OwmClouds {
+    all: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OwmCurrentResponse {
+    weather: Vec<OwmWeatherCondition>,
+    main: OwmMain,
+    wind: OwmWind,
+    clouds: OwmClouds,
+    #[serde(default)]
+    visibility: f32,
+    dt: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OwmForecastEntry {
+    dt: u64,
+    weather: Vec<OwmWeatherCondition>,
+    main: OwmMain,
+}
+
+#[derive(Debug, Deserialize)]
+struct OwmForecastResponse {
+    list: Vec<OwmForecastEntry>,
+}
+
+/// [`WeatherProvider`] backed by the OpenWeatherMap current weather and 5-day/3-hour forecast APIs.
+pub struct OpenWeatherMapProvider {
+    api_key: String,
+    units: &'static str,
+}
+
+impl OpenWeatherMapProvider {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        OpenWeatherMapProvider {
+            api_key: api_key.into(),
+            units: "metric",
+        }
+    }
+
+    pub fn imperial(api_key: impl Into<String>) -> Self {
+        OpenWeatherMapProvider {
+            api_key: api_key.into(),
+            units: "imperial",
+        }
+    }
+}
+
+#[async_trait]
+impl WeatherProvider for OpenWeatherMapProvider {
+    async fn fetch(&self, lat: Decimal, lng: Decimal) -> Result<Report, Error> {
+        let client = crate::http_client();
+
+        let current: OwmCurrentResponse = client
+            .get(OWM_WEATHER_URL)
+            .query(&[
+                ("lat", lat.to_string()),
+                ("lon", lng.to_string()),
+                ("appid", self.api_key.clone()),
+                ("units", self.units.to_string()),
+            ])
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        let forecast: OwmForecastResponse = client
+            .get(OWM_FORECAST_URL)
+            .query(&[
+                ("lat", lat.to_string()),
+                ("lon", lng.to_string()),
+                ("appid", self.api_key.clone()),
+                ("units", self.units.to_string()),
+            ])
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        let report_units = if self.units == "imperial" {
+            Units::Us
+        } else {
+            Units::Si
+        };
+        // OWM's `visibility` is always in meters, regardless of `units`.
+        let visibility_km = current.visibility / 1000.0;
+        let visibility = units::convert_distance(visibility_km, Units::Si, report_units);
+
+        let current_weather = current.weather.first();
+        let currently = Conditions {
+            time: current.dt,
+            condition: current_weather
+                .map(|w| Condition::from_owm_main(&w.main))
+                .unwrap_or(Condition::Unknown),
+            summary: current_weather
+                .map(|w| w.description.clone())
+                .unwrap_or_default(),
+            temperature: current.main.temp,
+            feels_like: current.main.feels_like,
+            humidity: current.main.humidity,
+            pressure: current.main.pressure,
+            wind_speed: current.wind.speed,
+            wind_bearing: current.wind.deg,
+            cloud_cover: current.clouds.all,
+            visibility,
+        };
+
+        let hourly = forecast
+            .list
+            .iter()
+            .map(|entry| {
+                let weather = entry.weather.first();
+                Forecast {
+                    time: entry.dt,
+                    condition: weather
+                        .map(|w| Condition::from_owm_main(&w.main))
+                        .unwrap_or(Condition::Unknown),
+                    summary: weather.map(|w| w.description.clone()).unwrap_or_default(),
+                    temperature: entry.main.temp,
+                    temperature_high: None,
+                    temperature_low: None,
+                }
+            })
+            .collect();
+
+        Ok(Report {
+            location: Location {
+                lat,
+                lng,
+                timezone: None,
+            },
+            currently,
+            hourly,
+            daily: Vec::new(),
+            data_source: "OpenWeatherMap".to_string(),
+            units: report_units,
+        })
+    }
+}
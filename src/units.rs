@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Distance, Pressure, Speed, Temperature};
+
+/// Unit system for a request or a [`crate::Report`], mirroring DarkSky's
+/// `units` query parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Units {
+    /// Fahrenheit, miles per hour, hectopascals, miles.
+    Us,
+    /// Celsius, meters per second, hectopascals, kilometers.
+    Si,
+    /// Same as `Si`, but wind speed in kilometers per hour.
+    Ca,
+    /// Same as `Si`, but wind speed in miles per hour and visibility in miles.
+    Uk,
+    /// Chosen by the provider based on the request's location.
+    Auto,
+}
+
+impl Units {
+    pub(crate) fn as_param(&self) -> &'static str {
+        match self {
+            Units::Us => "us",
+            Units::Si => "si",
+            Units::Ca => "ca",
+            Units::Uk => "uk2",
+            Units::Auto => "auto",
+        }
+    }
+
+    pub(crate) fn from_darksky_param(param: &str) -> Units {
+        match param {
+            "us" => Units::Us,
+            "si" => Units::Si,
+            "ca" => Units::Ca,
+            "uk2" => Units::Uk,
+            _ => Units::Auto,
+        }
+    }
+
+    fn uses_fahrenheit(self) -> bool {
+        matches!(self, Units::Us)
+    }
+
+    fn uses_miles(self) -> bool {
+        matches!(self, Units::Us | Units::Uk)
+    }
+
+    /// The temperature unit label to display alongside a value in this
+    /// system, e.g. in `Display` impls.
+    pub fn temperature_label(self) -> &'static str {
+        if self.uses_fahrenheit() {
+            "F"
+        } else {
+            "C"
+        }
+    }
+
+    fn wind_speed_unit(self) -> SpeedUnit {
+        match self {
+            Units::Us | Units::Uk => SpeedUnit::MilesPerHour,
+            Units::Ca => SpeedUnit::KilometersPerHour,
+            Units::Si | Units::Auto => SpeedUnit::MetersPerSecond,
+        }
+    }
+}
+
+enum SpeedUnit {
+    MetersPerSecond,
+    KilometersPerHour,
+    MilesPerHour,
+}
+
+impl SpeedUnit {
+    fn to_meters_per_second(self, value: Speed) -> Speed {
+        match self {
+            SpeedUnit::MetersPerSecond => value,
+            SpeedUnit::KilometersPerHour => value / 3.6,
+            SpeedUnit::MilesPerHour => value / 2.236_936,
+        }
+    }
+
+    fn from_meters_per_second(self, value: Speed) -> Speed {
+        match self {
+            SpeedUnit::MetersPerSecond => value,
+            SpeedUnit::KilometersPerHour => value * 3.6,
+            SpeedUnit::MilesPerHour => value * 2.236_936,
+        }
+    }
+}
+
+pub(crate) fn convert_temperature(value: Temperature, from: Units, to: Units) -> Temperature {
+    let celsius = if from.uses_fahrenheit() {
+        (value - 32.0) * 5.0 / 9.0
+    } else {
+        value
+    };
+    if to.uses_fahrenheit() {
+        celsius * 9.0 / 5.0 + 32.0
+    } else {
+        celsius
+    }
+}
+
+pub(crate) fn convert_speed(value: Speed, from: Units, to: Units) -> Speed {
+    let meters_per_second = from.wind_speed_unit().to_meters_per_second(value);
+    to.wind_speed_unit().from_meters_per_second(meters_per_second)
+}
+
+/// DarkSky reports pressure in hectopascals/millibars under every `units`
+/// value (including `us`), so there's nothing to rescale here.
+pub(crate) fn convert_pressure(value: Pressure, _from: Units, _to: Units) -> Pressure {
+    value
+}
+
+pub(crate) fn convert_distance(value: Distance, from: Units, to: Units) -> Distance {
+    if from.uses_miles() == to.uses_miles() {
+        return value;
+    }
+    let kilometers = if from.uses_miles() {
+        value / 0.621_371
+    } else {
+        value
+    };
+    if to.uses_miles() {
+        kilometers * 0.621_371
+    } else {
+        kilometers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn temperature_round_trips_us_si() {
+        assert!((convert_temperature(32.0, Units::Us, Units::Si) - 0.0).abs() < 0.01);
+        assert!((convert_temperature(0.0, Units::Si, Units::Us) - 32.0).abs() < 0.01);
+        assert!((convert_temperature(98.6, Units::Us, Units::Si) - 37.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn temperature_is_identity_within_celsius_systems() {
+        assert_eq!(convert_temperature(21.0, Units::Si, Units::Ca), 21.0);
+        assert_eq!(convert_temperature(21.0, Units::Si, Units::Uk), 21.0);
+    }
+
+    #[test]
+    fn speed_converts_between_every_unit() {
+        assert!((convert_speed(10.0, Units::Si, Units::Ca) - 36.0).abs() < 0.01);
+        assert!((convert_speed(10.0, Units::Si, Units::Us) - 22.369_36).abs() < 0.01);
+        assert!((convert_speed(100.0, Units::Ca, Units::Si) - 27.777_78).abs() < 0.01);
+    }
+
+    #[test]
+    fn pressure_never_rescales_between_any_units() {
+        for from in [Units::Us, Units::Si, Units::Ca, Units::Uk, Units::Auto] {
+            for to in [Units::Us, Units::Si, Units::Ca, Units::Uk, Units::Auto] {
+                assert_eq!(convert_pressure(1013.0, from, to), 1013.0);
+            }
+        }
+    }
+
+    #[test]
+    fn distance_converts_between_miles_and_kilometers() {
+        assert!((convert_distance(10.0, Units::Si, Units::Us) - 6.213_71).abs() < 0.01);
+        assert!((convert_distance(10.0, Units::Us, Units::Si) - 16.093_44).abs() < 0.01);
+        assert_eq!(convert_distance(10.0, Units::Us, Units::Uk), 10.0);
+    }
+}